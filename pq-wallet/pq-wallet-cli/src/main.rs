@@ -1,19 +1,25 @@
 //! pq-wallet — Post-quantum Ethereum wallet CLI
 //!
 //! Commands:
-//!   new      Generate a new ML-DSA-65 keypair and save to keystore
-//!   address  Show the address stored in a keystore (no passphrase needed)
-//!   balance  Query ETH balance via JSON-RPC
-//!   send     Build, sign and broadcast a PQ transaction
-//!   deploy   Deploy a contract (send tx with to=None, data=init code)
-//!   receipt  Poll for a transaction receipt
-//!   sign     Sign an arbitrary message and print the hex signature
+//!   new                Generate a new ML-DSA-65 keypair and save to keystore
+//!   address            Show the address stored in a keystore (no passphrase needed)
+//!   balance            Query ETH balance via JSON-RPC
+//!   send               Build, sign and broadcast a PQ transaction
+//!   deploy             Deploy a contract (send tx with to=None, data=init code)
+//!   receipt            Poll for a transaction receipt
+//!   sign               Sign an arbitrary message and print the hex signature
+//!   tx                 Offline signing flow (build / sign / send as separate steps)
+//!   import-seed        Create a keystore from an exported 32-byte seed
+//!   change-passphrase  Re-encrypt a keystore under a new passphrase
 
 use std::path::PathBuf;
 
 use anyhow::{Context, Result, bail};
 use clap::{Parser, Subcommand};
-use pq_wallet_core::{Keystore, PqKeypair, PqSigner, RpcClient, tx::PqTxRequest};
+use pq_wallet_core::{
+    HttpRemoteSigner, Keystore, PqKeypair, PqSignedTx, PqSigner, RpcClient, offline, remote,
+    tx::PqTxRequest,
+};
 
 // ─── CLI definition ───────────────────────────────────────────────────────────
 
@@ -97,6 +103,10 @@ enum Command {
         /// Dry run — print the signed tx hex without broadcasting.
         #[arg(long)]
         dry_run: bool,
+
+        /// Sign with an external JSON-RPC signer at this URL instead of a keystore.
+        #[arg(long, conflicts_with_all = ["keystore", "passphrase"])]
+        remote_signer: Option<String>,
     },
 
     /// Sign an arbitrary message and print the hex signature.
@@ -178,6 +188,13 @@ enum Command {
         rpc: String,
     },
 
+    /// Offline signing flow: build an unsigned tx, sign it on an air-gapped
+    /// machine, broadcast it separately.
+    Tx {
+        #[command(subcommand)]
+        command: TxCommand,
+    },
+
     /// Export the raw 32-byte ML-DSA-65 seed from a keystore.
     ///
     /// Outputs 64 hex chars to stdout — suitable for PQ_VALIDATOR_SK env var.
@@ -191,6 +208,134 @@ enum Command {
         #[arg(short, long)]
         passphrase: Option<String>,
     },
+
+    /// Create an encrypted keystore from a raw 32-byte ML-DSA-65 seed
+    /// (the hex printed by `export-seed`).
+    ImportSeed {
+        /// File containing the seed as 64 hex chars (e.g. a validator `seed-N.hex`).
+        /// If not provided, the seed is read from stdin. The prompt does NOT hide input.
+        #[arg(long)]
+        seed_file: Option<PathBuf>,
+
+        /// Output keystore file path.
+        #[arg(short, long, default_value = "keystore.json")]
+        output: PathBuf,
+
+        /// Passphrase to encrypt the keystore.
+        /// If not provided, you will be prompted.
+        #[arg(short, long)]
+        passphrase: Option<String>,
+
+        /// Overwrite the output file if it already exists.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Re-encrypt a keystore under a new passphrase.
+    ChangePassphrase {
+        /// Keystore file path.
+        #[arg(short, long, default_value = "keystore.json")]
+        keystore: PathBuf,
+
+        /// Current passphrase. If not provided, you will be prompted.
+        #[arg(long)]
+        old_passphrase: Option<String>,
+
+        /// New passphrase. If not provided, you will be prompted.
+        #[arg(long)]
+        new_passphrase: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum TxCommand {
+    /// Build an unsigned transaction and write it as JSON (online step).
+    ///
+    /// Chain ID, nonce and gas price are fetched from the node unless all
+    /// three are given explicitly.
+    Build {
+        /// Sender keystore (only the cleartext address is read).
+        #[arg(short, long, default_value = "keystore.json")]
+        keystore: PathBuf,
+
+        /// Sender address, for keys that only live in a remote signer.
+        /// Overrides --keystore.
+        #[arg(long, conflicts_with = "keystore")]
+        from: Option<String>,
+
+        /// Recipient address. Omit for contract creation.
+        #[arg(long)]
+        to: Option<String>,
+
+        /// Value to send in wei.
+        #[arg(long, default_value = "0")]
+        value: u128,
+
+        /// Gas limit (default: 21000).
+        #[arg(long, default_value = "21000")]
+        gas_limit: u64,
+
+        /// Gas price in wei. If not set, fetched from node.
+        #[arg(long)]
+        gas_price: Option<u128>,
+
+        /// Nonce. If not set, fetched from node.
+        #[arg(long)]
+        nonce: Option<u64>,
+
+        /// Chain ID. If not set, fetched from node.
+        #[arg(long)]
+        chain_id: Option<u64>,
+
+        /// Calldata / init code (hex, optional).
+        #[arg(long, default_value = "")]
+        data: String,
+
+        /// Output file for the unsigned transaction.
+        #[arg(short, long, default_value = "unsigned-tx.json")]
+        output: PathBuf,
+
+        /// JSON-RPC endpoint URL.
+        #[arg(short, long, default_value = "http://localhost:8545")]
+        rpc: String,
+    },
+
+    /// Sign an unsigned transaction file (offline step; only --remote-signer
+    /// touches the network).
+    Sign {
+        /// Unsigned transaction produced by `tx build`.
+        input: PathBuf,
+
+        /// Keystore file path.
+        #[arg(short, long, default_value = "keystore.json")]
+        keystore: PathBuf,
+
+        /// Passphrase to decrypt the keystore.
+        #[arg(short, long)]
+        passphrase: Option<String>,
+
+        /// Output file for the raw signed transaction.
+        #[arg(short, long, default_value = "signed-tx.hex")]
+        output: PathBuf,
+
+        /// Sign without asking for confirmation.
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Sign with an external JSON-RPC signer at this URL instead of a keystore.
+        #[arg(long, conflicts_with_all = ["keystore", "passphrase"])]
+        remote_signer: Option<String>,
+    },
+
+    /// Broadcast a raw signed transaction file (online step).
+    Send {
+        /// Signed transaction produced by `tx sign`.
+        input: PathBuf,
+
+        /// JSON-RPC endpoint URL.
+        #[arg(short, long, default_value = "http://localhost:8545")]
+        rpc: String,
+    },
 }
 
 // ─── Entry point ─────────────────────────────────────────────────────────────
@@ -203,8 +348,9 @@ async fn main() -> Result<()> {
         Command::New { output, passphrase } => cmd_new(output, passphrase).await,
         Command::Address { keystore } => cmd_address(keystore),
         Command::Balance { keystore, rpc } => cmd_balance(keystore, rpc).await,
-        Command::Send { keystore, passphrase, to, value, gas_limit, gas_price, data, rpc, dry_run } => {
-            cmd_send(keystore, passphrase, to, value, gas_limit, gas_price, data, rpc, dry_run).await
+        Command::Send { keystore, passphrase, to, value, gas_limit, gas_price, data, rpc, dry_run, remote_signer } => {
+            let signer = TxSigner::open(keystore, passphrase, remote_signer)?;
+            cmd_send(signer, to, value, gas_limit, gas_price, data, rpc, dry_run).await
         }
         Command::Deploy { keystore, passphrase, code, gas_limit, gas_price, value, rpc } => {
             cmd_deploy(keystore, passphrase, code, gas_limit, gas_price, value, rpc).await
@@ -212,7 +358,23 @@ async fn main() -> Result<()> {
         Command::Receipt { tx_hash, rpc, timeout } => cmd_receipt(tx_hash, rpc, timeout).await,
         Command::Sign { keystore, passphrase, message } => cmd_sign(keystore, passphrase, message),
         Command::Call { to, data, from, rpc } => cmd_call(to, data, from, rpc).await,
+        Command::Tx { command } => match command {
+            TxCommand::Build { keystore, from, to, value, gas_limit, gas_price, nonce, chain_id, data, output, rpc } => {
+                cmd_tx_build(keystore, from, to, value, gas_limit, gas_price, nonce, chain_id, data, output, rpc).await
+            }
+            TxCommand::Sign { input, keystore, passphrase, output, yes, remote_signer } => {
+                let signer = TxSigner::open(keystore, passphrase, remote_signer)?;
+                cmd_tx_sign(input, signer, output, yes).await
+            }
+            TxCommand::Send { input, rpc } => cmd_tx_send(input, rpc).await,
+        },
         Command::ExportSeed { keystore, passphrase } => cmd_export_seed(keystore, passphrase),
+        Command::ImportSeed { seed_file, output, passphrase, force } => {
+            cmd_import_seed(seed_file, output, passphrase, force)
+        }
+        Command::ChangePassphrase { keystore, old_passphrase, new_passphrase } => {
+            cmd_change_passphrase(keystore, old_passphrase, new_passphrase)
+        }
     }
 }
 
@@ -252,6 +414,63 @@ fn cmd_export_seed(keystore: PathBuf, passphrase: Option<String>) -> Result<()>
     Ok(())
 }
 
+fn cmd_import_seed(
+    seed_file: Option<PathBuf>,
+    output: PathBuf,
+    passphrase: Option<String>,
+    force: bool,
+) -> Result<()> {
+    if output.exists() && !force {
+        bail!("{} already exists; pass --force to overwrite it", output.display());
+    }
+
+    let seed = match seed_file {
+        Some(path) => std::fs::read_to_string(&path)
+            .with_context(|| format!("reading seed file {}", path.display()))?,
+        None => rpassword_prompt("Enter seed (hex, input is visible): ")?,
+    };
+    let seed = seed.trim();
+    let seed_bytes = hex::decode(seed.strip_prefix("0x").unwrap_or(seed)).context("decoding seed hex")?;
+    if seed_bytes.len() != 32 {
+        bail!("seed must be 32 bytes, got {}", seed_bytes.len());
+    }
+
+    let pass = resolve_passphrase(passphrase, true)?;
+    let keypair = Keystore::import_seed(&seed_bytes, &output, &pass, force)
+        .with_context(|| format!("saving keystore to {}", output.display()))?;
+
+    println!("Keystore saved to:  {}", output.display());
+    println!("Address:            {}", keypair.address());
+    Ok(())
+}
+
+fn cmd_change_passphrase(
+    keystore: PathBuf,
+    old_passphrase: Option<String>,
+    new_passphrase: Option<String>,
+) -> Result<()> {
+    let old = match old_passphrase {
+        Some(p) => p,
+        None => rpassword_prompt("Current passphrase: ")?,
+    };
+    let new = match new_passphrase {
+        Some(p) => p,
+        None => {
+            let new = rpassword_prompt("New passphrase: ")?;
+            if new != rpassword_prompt("Confirm new passphrase: ")? {
+                bail!("Passphrases do not match.");
+            }
+            new
+        }
+    };
+
+    Keystore::change_passphrase(&keystore, &old, &new)
+        .with_context(|| format!("re-encrypting keystore {}", keystore.display()))?;
+
+    println!("Passphrase changed for {}", keystore.display());
+    Ok(())
+}
+
 async fn cmd_balance(keystore: PathBuf, rpc: String) -> Result<()> {
     let address_str = Keystore::address_from_file(&keystore)
         .with_context(|| format!("reading keystore {}", keystore.display()))?;
@@ -271,8 +490,7 @@ async fn cmd_balance(keystore: PathBuf, rpc: String) -> Result<()> {
 
 #[allow(clippy::too_many_arguments)]
 async fn cmd_send(
-    keystore: PathBuf,
-    passphrase: Option<String>,
+    signer: TxSigner,
     to: String,
     value: u128,
     gas_limit: u64,
@@ -281,10 +499,6 @@ async fn cmd_send(
     rpc: String,
     dry_run: bool,
 ) -> Result<()> {
-    let pass = resolve_passphrase(passphrase, false)?;
-    let keypair = Keystore::load(&keystore, &pass)
-        .with_context(|| format!("decrypting keystore {}", keystore.display()))?;
-
     let client = RpcClient::new(&rpc);
 
     // Fetch chain_id, nonce, gas_price from node (unless dry_run skips RPC)
@@ -292,7 +506,7 @@ async fn cmd_send(
         (1u64, 0u64, gp)
     } else {
         let chain_id = client.chain_id().await.context("fetching chain_id")?;
-        let nonce = client.get_nonce(keypair.address()).await.context("fetching nonce")?;
+        let nonce = client.get_nonce(signer.address().await?).await.context("fetching nonce")?;
         let gp = match gas_price {
             Some(p) => p,
             None => client.gas_price().await.context("fetching gas_price")?,
@@ -317,10 +531,9 @@ async fn cmd_send(
         input,
     };
 
-    let signer = PqSigner::new(&keypair);
-    let signed = signer.sign(tx);
+    let signed = signer.sign(tx).await?;
 
-    let raw_hex = format!("0x{}", hex::encode(signed.encode()));
+    let raw_hex = signed.raw_hex();
 
     if dry_run {
         println!("Tx hash (local):  {}", signed.hash);
@@ -456,6 +669,171 @@ async fn cmd_call(to: String, data: String, from: Option<String>, rpc: String) -
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
+async fn cmd_tx_build(
+    keystore: PathBuf,
+    from: Option<String>,
+    to: Option<String>,
+    value: u128,
+    gas_limit: u64,
+    gas_price: Option<u128>,
+    nonce: Option<u64>,
+    chain_id: Option<u64>,
+    data: String,
+    output: PathBuf,
+    rpc: String,
+) -> Result<()> {
+    let from = match from {
+        Some(addr) => parse_address(&addr)?,
+        None => {
+            let address_str = Keystore::address_from_file(&keystore)
+                .with_context(|| format!("reading keystore {}", keystore.display()))?;
+            parse_address(&address_str)?
+        }
+    };
+
+    let client = RpcClient::new(&rpc);
+
+    let chain_id = match chain_id {
+        Some(id) => id,
+        None => client.chain_id().await.context("fetching chain_id")?,
+    };
+    let nonce = match nonce {
+        Some(n) => n,
+        None => client.get_nonce(from).await.context("fetching nonce")?,
+    };
+    let gas_price = match gas_price {
+        Some(p) => p,
+        None => client.gas_price().await.context("fetching gas_price")?,
+    };
+
+    let to = to.as_deref().map(parse_address).transpose()?;
+    let input = if data.is_empty() {
+        vec![]
+    } else {
+        hex::decode(data.strip_prefix("0x").unwrap_or(&data)).context("decoding calldata hex")?
+    };
+
+    let tx = PqTxRequest { chain_id, nonce, to, value, gas_limit, gas_price, input };
+    let signing_hash = tx.signing_hash();
+
+    offline::write_unsigned(&offline::UnsignedTx { from, tx }, &output)
+        .with_context(|| format!("writing unsigned tx to {}", output.display()))?;
+
+    println!("Unsigned tx saved to: {}", output.display());
+    println!("From:                 {from}");
+    println!("Nonce:                {nonce}");
+    println!("Signing hash:         {signing_hash}");
+    println!();
+    println!("Copy it to the signing machine and run `pq-wallet tx sign {}`.", output.display());
+
+    Ok(())
+}
+
+async fn cmd_tx_sign(input: PathBuf, signer: TxSigner, output: PathBuf, yes: bool) -> Result<()> {
+    let unsigned = offline::read_unsigned(&input)
+        .with_context(|| format!("reading unsigned tx {}", input.display()))?;
+
+    // The nonce was fetched for `from`; any other key would sign at the wrong nonce.
+    unsigned
+        .ensure_signer(signer.address().await?)
+        .with_context(|| format!("{} does not match the unsigned tx", signer.describe()))?;
+
+    // Show what is being signed — the air-gapped machine is the only place
+    // the user can trust these values.
+    let tx = unsigned.tx;
+    println!("From:       {}", unsigned.from);
+    println!("Chain ID:   {}", tx.chain_id);
+    println!("Nonce:      {}", tx.nonce);
+    match tx.to {
+        Some(to) => println!("To:         {to}"),
+        None => println!("To:         (contract creation)"),
+    }
+    println!("Value:      {} wei", tx.value);
+    println!("Gas limit:  {}", tx.gas_limit);
+    println!("Gas price:  {} wei", tx.gas_price);
+    println!("Data:       0x{} ({} bytes)", hex::encode(&tx.input), tx.input.len());
+    println!();
+
+    if !yes && !confirm("Sign this transaction? [y/N] ")? {
+        bail!("Aborted, nothing was signed.");
+    }
+
+    let signed = signer.sign(tx).await?;
+
+    offline::write_signed(&signed, &output)
+        .with_context(|| format!("writing signed tx to {}", output.display()))?;
+
+    println!("Tx hash:    {}", signed.hash);
+    println!("Signed tx saved to: {}", output.display());
+
+    Ok(())
+}
+
+async fn cmd_tx_send(input: PathBuf, rpc: String) -> Result<()> {
+    let raw_hex = offline::read_signed_raw(&input)
+        .with_context(|| format!("reading signed tx {}", input.display()))?;
+
+    let client = RpcClient::new(&rpc);
+
+    print!("Broadcasting transaction... ");
+    let tx_hash = client.send_raw_transaction(&raw_hex).await.context("broadcasting transaction")?;
+    println!("done.");
+    println!("Transaction hash: {tx_hash}");
+
+    Ok(())
+}
+
+// ─── Transaction signer ──────────────────────────────────────────────────────
+
+/// Where `send` and `tx sign` get their signatures from.
+enum TxSigner {
+    /// A keypair decrypted from a local keystore.
+    Keystore { path: PathBuf, keypair: Box<PqKeypair> },
+    /// An external signer reached over JSON-RPC (see [`remote`]).
+    Remote { url: String, signer: HttpRemoteSigner },
+}
+
+impl TxSigner {
+    /// Use the remote signer at `remote_url` if given, otherwise decrypt `keystore`.
+    fn open(keystore: PathBuf, passphrase: Option<String>, remote_url: Option<String>) -> Result<Self> {
+        if let Some(url) = remote_url {
+            let signer = HttpRemoteSigner::new(&url);
+            return Ok(Self::Remote { url, signer });
+        }
+
+        let pass = resolve_passphrase(passphrase, false)?;
+        let keypair = Keystore::load(&keystore, &pass)
+            .with_context(|| format!("decrypting keystore {}", keystore.display()))?;
+        Ok(Self::Keystore { path: keystore, keypair: Box::new(keypair) })
+    }
+
+    async fn address(&self) -> Result<alloy_primitives::Address> {
+        match self {
+            Self::Keystore { keypair, .. } => Ok(keypair.address()),
+            Self::Remote { url, signer } => remote::signer_address(signer)
+                .await
+                .with_context(|| format!("fetching public key from remote signer {url}")),
+        }
+    }
+
+    async fn sign(&self, tx: PqTxRequest) -> Result<PqSignedTx> {
+        match self {
+            Self::Keystore { keypair, .. } => Ok(PqSigner::new(keypair).sign(tx)),
+            Self::Remote { url, signer } => remote::sign_tx(signer, tx)
+                .await
+                .with_context(|| format!("signing with remote signer {url}")),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Keystore { path, .. } => format!("keystore {}", path.display()),
+            Self::Remote { url, .. } => format!("remote signer {url}"),
+        }
+    }
+}
+
 // ─── Helpers ─────────────────────────────────────────────────────────────────
 
 /// Prompt for a passphrase if not provided via flag.
@@ -474,7 +852,9 @@ fn resolve_passphrase(provided: Option<String>, confirm: bool) -> Result<String>
     Ok(pass)
 }
 
-/// Read a passphrase from stdin without echoing (simple version using print+stdin).
+/// Read a line from stdin after printing `prompt`.
+///
+/// Input is echoed to the terminal — this does not hide passphrases or seeds.
 fn rpassword_prompt(prompt: &str) -> Result<String> {
     use std::io::{self, Write};
     print!("{prompt}");
@@ -484,6 +864,12 @@ fn rpassword_prompt(prompt: &str) -> Result<String> {
     Ok(line.trim().to_string())
 }
 
+/// Ask a yes/no question on stdin. Anything but `y` / `yes` means no.
+fn confirm(prompt: &str) -> Result<bool> {
+    let answer = rpassword_prompt(prompt)?;
+    Ok(matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
 /// Parse a hex address string (with or without 0x prefix) into `Address`.
 fn parse_address(s: &str) -> Result<alloy_primitives::Address> {
     let s = s.strip_prefix("0x").unwrap_or(s);
@@ -23,6 +23,10 @@ pub enum WalletError {
     #[error("invalid signing key bytes")]
     InvalidSigningKey,
 
+    /// A remote signer returned a malformed key or signature.
+    #[error("remote signer error: {0}")]
+    RemoteSigner(String),
+
     // ── RPC ───────────────────────────────────────────────────────────────────
     /// HTTP transport error.
     #[error("RPC transport error: {0}")]
@@ -40,4 +44,8 @@ pub enum WalletError {
     /// Hex decoding of an address or hash failed.
     #[error("hex decode error: {0}")]
     HexDecode(#[from] hex::FromHexError),
+
+    /// A raw transaction is malformed or not a PQ transaction.
+    #[error("invalid transaction: {0}")]
+    InvalidTransaction(String),
 }
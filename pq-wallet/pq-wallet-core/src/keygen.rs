@@ -58,12 +58,7 @@ impl PqKeypair {
     ///
     /// Uses SHAKE-256 (XOF) aligned with ML-DSA-65 which uses SHAKE-256 internally.
     pub fn address(&self) -> Address {
-        let pk_bytes = self.public_key_bytes();
-        let mut hasher = Shake256::default();
-        hasher.update(&pk_bytes);
-        let mut hash = [0u8; 32];
-        hasher.finalize_xof().read(&mut hash);
-        Address::from_slice(&hash[12..])
+        address_from_public_key(&self.public_key_bytes())
     }
 
     /// Save this keypair to an encrypted keystore file.
@@ -77,3 +72,13 @@ impl PqKeypair {
         self.sk.sign(msg).encode().as_slice().to_vec()
     }
 }
+
+/// Derive the Ethereum address of raw ML-DSA-65 public key bytes:
+/// `shake256(pk_bytes, 32)[12..]`.
+pub fn address_from_public_key(pk_bytes: &[u8]) -> Address {
+    let mut hasher = Shake256::default();
+    hasher.update(pk_bytes);
+    let mut hash = [0u8; 32];
+    hasher.finalize_xof().read(&mut hash);
+    Address::from_slice(&hash[12..])
+}
@@ -24,7 +24,11 @@
 //! - Encryption: AES-256-GCM (authenticated)
 //! - The 32-byte seed is encrypted (not the full expanded key)
 
-use std::path::Path;
+use std::{
+    fs::{OpenOptions, Permissions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
 
 use aes_gcm::{
     aead::{Aead, KeyInit},
@@ -92,6 +96,13 @@ const ARGON2_P_COST: u32 = 4;
 impl Keystore {
     /// Encrypt `keypair` with `passphrase` and write to `path`.
     pub fn save(keypair: &PqKeypair, path: &Path, passphrase: &str) -> Result<(), WalletError> {
+        let json = Self::encrypt_to_json(keypair, passphrase)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Encrypt `keypair` with `passphrase` into keystore JSON.
+    fn encrypt_to_json(keypair: &PqKeypair, passphrase: &str) -> Result<String, WalletError> {
         let mut rng = rand::thread_rng();
 
         // Random salt (16 bytes) and IV (12 bytes)
@@ -131,9 +142,7 @@ impl Keystore {
             },
         };
 
-        let json = serde_json::to_string_pretty(&ks)?;
-        std::fs::write(path, json)?;
-        Ok(())
+        Ok(serde_json::to_string_pretty(&ks)?)
     }
 
     /// Load and decrypt a keystore file with `passphrase`.
@@ -159,6 +168,46 @@ impl Keystore {
         PqKeypair::from_seed_bytes(&seed_bytes)
     }
 
+    /// Re-encrypt the keystore at `path` under `new_passphrase`.
+    ///
+    /// A fresh salt and IV are generated. The file is replaced atomically and
+    /// keeps its permissions, so a crash mid-rotation leaves either the old or
+    /// the new keystore intact.
+    pub fn change_passphrase(
+        path: &Path,
+        old_passphrase: &str,
+        new_passphrase: &str,
+    ) -> Result<(), WalletError> {
+        let keypair = Self::load(path, old_passphrase)?;
+        let json = Self::encrypt_to_json(&keypair, new_passphrase)?;
+        replace_synced(path, &json)?;
+        Ok(())
+    }
+
+    /// Import a raw 32-byte seed (as printed by `export-seed`) into a new
+    /// keystore at `path`.
+    ///
+    /// Refuses to replace an existing file (an [`std::io::ErrorKind::AlreadyExists`]
+    /// I/O error) unless `overwrite` is set — the existing file may hold the
+    /// only copy of another key. With `overwrite`, the file is replaced
+    /// atomically, as in [`Keystore::change_passphrase`].
+    pub fn import_seed(
+        seed: &[u8],
+        path: &Path,
+        passphrase: &str,
+        overwrite: bool,
+    ) -> Result<PqKeypair, WalletError> {
+        let keypair = PqKeypair::from_seed_bytes(seed)?;
+        let json = Self::encrypt_to_json(&keypair, passphrase)?;
+
+        if overwrite {
+            replace_synced(path, &json)?;
+        } else {
+            write_new_synced(path, &json, None)?;
+        }
+        Ok(keypair)
+    }
+
     /// Return just the address from a keystore file without decrypting.
     pub fn address_from_file(path: &Path) -> Result<String, WalletError> {
         let json = std::fs::read_to_string(path)?;
@@ -176,6 +225,46 @@ impl Keystore {
 
 // ─── Internal helpers ────────────────────────────────────────────────────────
 
+/// Write `contents` to a file that must not exist yet and fsync it.
+///
+/// The file is removed again if anything after creating it fails.
+fn write_new_synced(path: &Path, contents: &str, permissions: Option<Permissions>) -> io::Result<()> {
+    let mut file = OpenOptions::new().write(true).create_new(true).open(path)?;
+    let result = (|| {
+        if let Some(permissions) = permissions {
+            file.set_permissions(permissions)?;
+        }
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()
+    })();
+    if result.is_err() {
+        drop(file);
+        let _ = std::fs::remove_file(path);
+    }
+    result
+}
+
+/// Atomically replace `path` with `contents`.
+///
+/// The contents go to a uniquely named sibling file that takes over the
+/// original's permissions and is fsynced before being renamed over `path`.
+fn replace_synced(path: &Path, contents: &str) -> io::Result<()> {
+    let permissions = match std::fs::metadata(path) {
+        Ok(meta) => Some(meta.permissions()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{:016x}.tmp", rand::random::<u64>()));
+    let tmp = PathBuf::from(tmp);
+
+    write_new_synced(&tmp, contents, permissions)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
+}
+
 fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], WalletError> {
     let params = Params::new(ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST, Some(32))
         .map_err(|e| WalletError::RpcParse(e.to_string()))?;
@@ -228,6 +317,88 @@ mod tests {
         assert!(matches!(result.unwrap_err(), WalletError::DecryptionFailed));
     }
 
+    #[test]
+    fn change_passphrase_rotates_encryption() {
+        let keypair = PqKeypair::generate();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+        Keystore::save(&keypair, &path, "old-pass").unwrap();
+
+        Keystore::change_passphrase(&path, "old-pass", "new-pass").unwrap();
+
+        assert!(matches!(
+            Keystore::load(&path, "old-pass").unwrap_err(),
+            WalletError::DecryptionFailed
+        ));
+        let loaded = Keystore::load(&path, "new-pass").unwrap();
+        assert_eq!(loaded.address(), keypair.address());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1, "temp file must not be left behind");
+    }
+
+    #[test]
+    fn change_passphrase_leaves_unrelated_tmp_file_alone() {
+        let keypair = PqKeypair::generate();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+        let unrelated = dir.path().join("keystore.json.tmp");
+        Keystore::save(&keypair, &path, "old-pass").unwrap();
+        std::fs::write(&unrelated, "not ours").unwrap();
+
+        Keystore::change_passphrase(&path, "old-pass", "new-pass").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&unrelated).unwrap(), "not ours");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn change_passphrase_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let keypair = PqKeypair::generate();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+        Keystore::save(&keypair, &path, "old-pass").unwrap();
+        std::fs::set_permissions(&path, Permissions::from_mode(0o600)).unwrap();
+
+        Keystore::change_passphrase(&path, "old-pass", "new-pass").unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn import_seed_matches_original_key() {
+        let keypair = PqKeypair::generate();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keystore.json");
+
+        let imported = Keystore::import_seed(&keypair.seed_bytes(), &path, "pass", false).unwrap();
+        assert_eq!(imported.address(), keypair.address());
+
+        let loaded = Keystore::load(&path, "pass").unwrap();
+        assert_eq!(loaded.public_key_bytes(), keypair.public_key_bytes());
+    }
+
+    #[test]
+    fn import_seed_refuses_to_overwrite() {
+        let existing = PqKeypair::generate();
+        let file = NamedTempFile::new().unwrap();
+        Keystore::save(&existing, file.path(), "pass").unwrap();
+
+        let other = PqKeypair::generate();
+        let result = Keystore::import_seed(&other.seed_bytes(), file.path(), "pass", false);
+        assert!(matches!(
+            result,
+            Err(WalletError::Io(ref e)) if e.kind() == std::io::ErrorKind::AlreadyExists
+        ));
+        let loaded = Keystore::load(file.path(), "pass").unwrap();
+        assert_eq!(loaded.address(), existing.address(), "existing key must survive");
+
+        Keystore::import_seed(&other.seed_bytes(), file.path(), "pass", true).unwrap();
+        let loaded = Keystore::load(file.path(), "pass").unwrap();
+        assert_eq!(loaded.address(), other.address());
+    }
+
     #[test]
     fn address_from_file_does_not_need_passphrase() {
         let keypair = PqKeypair::generate();
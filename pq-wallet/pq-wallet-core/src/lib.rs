@@ -7,6 +7,8 @@
 //! - [`keystore`] — encrypted key storage on disk (AES-256-GCM + Argon2id)
 //! - [`signer`] — transaction signing
 //! - [`rpc`] — minimal JSON-RPC client (balance, nonce, send)
+//! - [`remote`] — [`RemoteSigner`] trait for keys held by an external process / HSM
+//! - [`offline`] — build / sign / broadcast split for air-gapped signing
 //!
 //! # Example
 //!
@@ -30,6 +32,8 @@
 pub mod error;
 pub mod keygen;
 pub mod keystore;
+pub mod offline;
+pub mod remote;
 pub mod rpc;
 pub mod signer;
 pub mod tx;
@@ -37,6 +41,7 @@ pub mod tx;
 pub use error::WalletError;
 pub use keygen::PqKeypair;
 pub use keystore::Keystore;
+pub use remote::{HttpRemoteSigner, RemoteSigner};
 pub use rpc::{RpcClient, TxReceipt};
 pub use signer::PqSigner;
-pub use tx::{PqTxRequest, PQ_TX_TYPE};
+pub use tx::{PqSignedTx, PqTxRequest, PQ_TX_TYPE};
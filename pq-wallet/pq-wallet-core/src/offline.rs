@@ -0,0 +1,144 @@
+//! Offline (air-gapped) signing flow.
+//!
+//! Splits [`send`](crate::rpc::RpcClient::send_raw_transaction) into three
+//! steps so the signing key never has to touch a networked machine:
+//!
+//! 1. **Build** (online) — fetch chain ID, nonce and gas price for the sender,
+//!    then write an [`UnsignedTx`] as JSON with [`write_unsigned`].
+//! 2. **Sign** (offline) — load it with [`read_unsigned`], check the key with
+//!    [`UnsignedTx::ensure_signer`], sign with a
+//!    [`PqSigner`](crate::signer::PqSigner) and write the raw transaction
+//!    with [`write_signed`].
+//! 3. **Broadcast** (online) — load the raw transaction with
+//!    [`read_signed_raw`] and pass it to `eth_sendRawTransaction`.
+//!
+//! The signed file contains a single line: the `0x`-prefixed hex encoding of
+//! the EIP-2718 envelope (`0x50 || RLP(...)`), so it can also be broadcast by
+//! any other Ethereum tooling.
+
+use std::path::Path;
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::WalletError,
+    tx::{PqSignedTx, PqTxRequest, PQ_TX_TYPE},
+};
+
+/// An unsigned transaction together with the sender it was built for.
+///
+/// The nonce in `tx` is only meaningful for `from`, so the signing step must
+/// refuse any other key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedTx {
+    /// Address whose nonce was used to build `tx`.
+    pub from: Address,
+    /// The transaction fields to sign.
+    pub tx: PqTxRequest,
+}
+
+impl UnsignedTx {
+    /// Fail with [`WalletError::InvalidTransaction`] unless `signer` is the
+    /// address this transaction was built for.
+    pub fn ensure_signer(&self, signer: Address) -> Result<(), WalletError> {
+        if signer != self.from {
+            return Err(WalletError::InvalidTransaction(format!(
+                "built for {}, but signing key is {signer}",
+                self.from
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Write an unsigned transaction to `path` as pretty-printed JSON.
+pub fn write_unsigned(unsigned: &UnsignedTx, path: &Path) -> Result<(), WalletError> {
+    let json = serde_json::to_string_pretty(unsigned)?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Read an unsigned transaction previously written by [`write_unsigned`].
+pub fn read_unsigned(path: &Path) -> Result<UnsignedTx, WalletError> {
+    let json = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Write a signed transaction to `path` as a single `0x`-prefixed hex line.
+pub fn write_signed(signed: &PqSignedTx, path: &Path) -> Result<(), WalletError> {
+    std::fs::write(path, format!("{}\n", signed.raw_hex()))?;
+    Ok(())
+}
+
+/// Read a raw signed transaction written by [`write_signed`].
+///
+/// Returns the `0x`-prefixed hex string, ready for `eth_sendRawTransaction`.
+/// Fails with [`WalletError::InvalidTransaction`] if the payload is not a
+/// PQ transaction (type `0x50`).
+pub fn read_signed_raw(path: &Path) -> Result<String, WalletError> {
+    let contents = std::fs::read_to_string(path)?;
+    let trimmed = contents.trim();
+    let bytes = hex::decode(trimmed.strip_prefix("0x").unwrap_or(trimmed))?;
+    match bytes.first() {
+        Some(&PQ_TX_TYPE) => Ok(format!("0x{}", hex::encode(bytes))),
+        Some(other) => Err(WalletError::InvalidTransaction(format!(
+            "expected tx type 0x{PQ_TX_TYPE:02x}, got 0x{other:02x}"
+        ))),
+        None => Err(WalletError::InvalidTransaction("empty transaction file".into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keygen::PqKeypair, signer::PqSigner, tx::sample_transfer};
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn unsigned_roundtrip() {
+        let unsigned = UnsignedTx {
+            from: Address::from([0x11; 20]),
+            tx: PqTxRequest { nonce: 7, input: vec![0xde, 0xad, 0xbe, 0xef], ..sample_transfer() },
+        };
+        let file = NamedTempFile::new().unwrap();
+        write_unsigned(&unsigned, file.path()).unwrap();
+
+        let loaded = read_unsigned(file.path()).unwrap();
+        assert_eq!(loaded, unsigned);
+        assert_eq!(loaded.tx.signing_hash(), unsigned.tx.signing_hash());
+    }
+
+    #[test]
+    fn ensure_signer_rejects_other_key() {
+        let builder = PqKeypair::generate();
+        let other = PqKeypair::generate();
+        let unsigned = UnsignedTx { from: builder.address(), tx: sample_transfer() };
+
+        assert!(unsigned.ensure_signer(builder.address()).is_ok());
+        assert!(matches!(
+            unsigned.ensure_signer(other.address()),
+            Err(WalletError::InvalidTransaction(_))
+        ));
+    }
+
+    #[test]
+    fn signed_roundtrip_matches_raw_hex() {
+        let kp = PqKeypair::generate();
+        let signed = PqSigner::new(&kp).sign(sample_transfer());
+        let file = NamedTempFile::new().unwrap();
+        write_signed(&signed, file.path()).unwrap();
+
+        let raw = read_signed_raw(file.path()).unwrap();
+        assert_eq!(raw, signed.raw_hex());
+    }
+
+    #[test]
+    fn read_signed_rejects_wrong_tx_type() {
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "0x02c0").unwrap();
+
+        let result = read_signed_raw(file.path());
+        assert!(matches!(result, Err(WalletError::InvalidTransaction(_))));
+    }
+}
@@ -0,0 +1,232 @@
+//! Remote signing — keep the ML-DSA-65 key outside the calling process.
+//!
+//! [`RemoteSigner`] abstracts "something that can sign a 32-byte hash and
+//! tell us its public key". Implementations:
+//!
+//! - [`PqSigner`] — the in-memory wallet key (useful for tests and for code
+//!   that should not care where the key lives).
+//! - [`HttpRemoteSigner`] — an external signer process or HSM bridge reached
+//!   over JSON-RPC 2.0.
+//!
+//! # Protocol
+//!
+//! The external signer must answer two JSON-RPC methods:
+//!
+//! | Method          | Params              | Result                         |
+//! |-----------------|---------------------|--------------------------------|
+//! | `pq_publicKey`  | `[]`                | hex ML-DSA-65 public key (1952 bytes) |
+//! | `pq_signHash`   | `["0x<32-byte hash>"]` | hex ML-DSA-65 signature (3309 bytes) |
+//!
+//! The signer only ever receives the transaction's signing hash; the key
+//! never leaves it. Every signature it returns is verified against its public
+//! key before a transaction is assembled.
+
+use std::future::Future;
+
+use alloy_primitives::{Address, B256};
+use dilithium::{MlDsa65, Signature, VerifyingKey, signature::Verifier};
+use hybrid_array::Array;
+use serde_json::{Value, json};
+use tokio::sync::OnceCell;
+
+use crate::{
+    error::WalletError,
+    keygen::address_from_public_key,
+    rpc::RpcClient,
+    signer::PqSigner,
+    tx::{PqSignedTx, PqTxRequest},
+};
+
+/// Encoded ML-DSA-65 public key length.
+const PUBLIC_KEY_LEN: usize = 1952;
+/// Encoded ML-DSA-65 signature length.
+const SIGNATURE_LEN: usize = 3309;
+
+// ─── Trait ───────────────────────────────────────────────────────────────────
+
+/// A signer whose private key may live outside this process.
+pub trait RemoteSigner {
+    /// Raw encoded ML-DSA-65 public key bytes.
+    fn public_key(&self) -> impl Future<Output = Result<Vec<u8>, WalletError>> + Send;
+
+    /// Sign a 32-byte hash and return the raw signature bytes.
+    fn sign_hash(&self, hash: B256) -> impl Future<Output = Result<Vec<u8>, WalletError>> + Send;
+}
+
+/// Sign `tx` with any [`RemoteSigner`] and return a [`PqSignedTx`].
+///
+/// The returned signature is verified against the signer's public key over
+/// `tx.signing_hash()` before the transaction is assembled, so a misbehaving
+/// or compromised signer cannot produce an invalid payload.
+pub async fn sign_tx<S: RemoteSigner>(signer: &S, tx: PqTxRequest) -> Result<PqSignedTx, WalletError> {
+    let pk_bytes = signer.public_key().await?;
+    check_len("public key", &pk_bytes, PUBLIC_KEY_LEN)?;
+
+    let hash = tx.signing_hash();
+    let sig_bytes = signer.sign_hash(hash).await?;
+    check_len("signature", &sig_bytes, SIGNATURE_LEN)?;
+    verify_signature(&pk_bytes, hash, &sig_bytes)?;
+
+    Ok(PqSignedTx::new(tx, sig_bytes, pk_bytes))
+}
+
+/// Address controlled by a [`RemoteSigner`].
+pub async fn signer_address<S: RemoteSigner>(signer: &S) -> Result<Address, WalletError> {
+    let pk_bytes = signer.public_key().await?;
+    check_len("public key", &pk_bytes, PUBLIC_KEY_LEN)?;
+    Ok(address_from_public_key(&pk_bytes))
+}
+
+// ─── In-memory implementation ────────────────────────────────────────────────
+
+impl RemoteSigner for PqSigner<'_> {
+    async fn public_key(&self) -> Result<Vec<u8>, WalletError> {
+        Ok(self.keypair().public_key_bytes())
+    }
+
+    async fn sign_hash(&self, hash: B256) -> Result<Vec<u8>, WalletError> {
+        Ok(self.sign_hash_bytes(hash))
+    }
+}
+
+// ─── JSON-RPC implementation ─────────────────────────────────────────────────
+
+/// A [`RemoteSigner`] backed by an external process speaking JSON-RPC 2.0.
+///
+/// The public key is fetched on first use and cached for the lifetime of the
+/// signer.
+pub struct HttpRemoteSigner {
+    client: RpcClient,
+    pk_bytes: OnceCell<Vec<u8>>,
+}
+
+impl HttpRemoteSigner {
+    /// Create a signer pointing to `url` (e.g. `http://127.0.0.1:9545`).
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { client: RpcClient::new(url), pk_bytes: OnceCell::new() }
+    }
+}
+
+impl RemoteSigner for HttpRemoteSigner {
+    async fn public_key(&self) -> Result<Vec<u8>, WalletError> {
+        let pk_bytes = self
+            .pk_bytes
+            .get_or_try_init(|| async {
+                let result = self.client.call("pq_publicKey", json!([])).await?;
+                decode_hex_result(&result)
+            })
+            .await?;
+        Ok(pk_bytes.clone())
+    }
+
+    async fn sign_hash(&self, hash: B256) -> Result<Vec<u8>, WalletError> {
+        let result = self.client.call("pq_signHash", json!([format!("{hash}")])).await?;
+        decode_hex_result(&result)
+    }
+}
+
+// ─── Helpers ─────────────────────────────────────────────────────────────────
+
+fn decode_hex_result(v: &Value) -> Result<Vec<u8>, WalletError> {
+    let s = v
+        .as_str()
+        .ok_or_else(|| WalletError::RemoteSigner("expected hex string".into()))?;
+    Ok(hex::decode(s.strip_prefix("0x").unwrap_or(s))?)
+}
+
+/// Verify an encoded ML-DSA-65 signature over `hash`.
+fn verify_signature(pk_bytes: &[u8], hash: B256, sig_bytes: &[u8]) -> Result<(), WalletError> {
+    let pk_enc = Array::try_from(pk_bytes)
+        .map_err(|_| WalletError::RemoteSigner("malformed public key".into()))?;
+    let vk = VerifyingKey::<MlDsa65>::decode(&pk_enc);
+
+    let sig_enc = Array::try_from(sig_bytes)
+        .map_err(|_| WalletError::RemoteSigner("malformed signature".into()))?;
+    let sig = Signature::<MlDsa65>::decode(&sig_enc)
+        .ok_or_else(|| WalletError::RemoteSigner("malformed signature".into()))?;
+
+    vk.verify(hash.as_slice(), &sig).map_err(|_| {
+        WalletError::RemoteSigner("signature does not verify against the signer's public key".into())
+    })
+}
+
+fn check_len(what: &str, bytes: &[u8], expected: usize) -> Result<(), WalletError> {
+    if bytes.len() != expected {
+        return Err(WalletError::RemoteSigner(format!(
+            "{what} must be {expected} bytes, got {}",
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{keygen::PqKeypair, tx::sample_transfer};
+
+    /// Signer that returns a truncated signature.
+    struct TruncatingSigner<'a>(PqSigner<'a>);
+
+    impl RemoteSigner for TruncatingSigner<'_> {
+        async fn public_key(&self) -> Result<Vec<u8>, WalletError> {
+            self.0.public_key().await
+        }
+
+        async fn sign_hash(&self, hash: B256) -> Result<Vec<u8>, WalletError> {
+            let mut sig = self.0.sign_hash(hash).await?;
+            sig.truncate(64);
+            Ok(sig)
+        }
+    }
+
+    /// Signer that reports one key but signs with another.
+    struct MismatchedSigner<'a> {
+        reported: PqSigner<'a>,
+        actual: PqSigner<'a>,
+    }
+
+    impl RemoteSigner for MismatchedSigner<'_> {
+        async fn public_key(&self) -> Result<Vec<u8>, WalletError> {
+            self.reported.public_key().await
+        }
+
+        async fn sign_hash(&self, hash: B256) -> Result<Vec<u8>, WalletError> {
+            self.actual.sign_hash(hash).await
+        }
+    }
+
+    #[tokio::test]
+    async fn in_memory_signer_matches_keypair() {
+        let kp = PqKeypair::generate();
+        let signer = PqSigner::new(&kp);
+
+        assert_eq!(signer_address(&signer).await.unwrap(), kp.address());
+
+        let signed = sign_tx(&signer, sample_transfer()).await.unwrap();
+        assert_eq!(signed.pk_bytes, kp.public_key_bytes());
+        assert_eq!(signed.sig_bytes.len(), SIGNATURE_LEN);
+    }
+
+    #[tokio::test]
+    async fn malformed_signature_is_rejected() {
+        let kp = PqKeypair::generate();
+        let signer = TruncatingSigner(PqSigner::new(&kp));
+
+        let result = sign_tx(&signer, sample_transfer()).await;
+        assert!(matches!(result, Err(WalletError::RemoteSigner(_))));
+    }
+
+    #[tokio::test]
+    async fn signature_from_other_key_is_rejected() {
+        let reported = PqKeypair::generate();
+        let actual = PqKeypair::generate();
+        let signer = MismatchedSigner {
+            reported: PqSigner::new(&reported),
+            actual: PqSigner::new(&actual),
+        };
+
+        let result = sign_tx(&signer, sample_transfer()).await;
+        assert!(matches!(result, Err(WalletError::RemoteSigner(_))));
+    }
+}
@@ -181,7 +181,7 @@ impl RpcClient {
 
     // ── Internal ──────────────────────────────────────────────────────────────
 
-    async fn call(&self, method: &str, params: Value) -> Result<Value, WalletError> {
+    pub(crate) async fn call(&self, method: &str, params: Value) -> Result<Value, WalletError> {
         let req = RpcRequest { jsonrpc: "2.0", method, params, id: 1 };
         let resp: RpcResponse = self.client.post(&self.url).json(&req).send().await?.json().await?;
 
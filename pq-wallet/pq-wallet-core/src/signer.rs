@@ -1,5 +1,6 @@
 //! Transaction signer — combines a [`PqKeypair`] with [`PqTxRequest`].
 
+use alloy_primitives::B256;
 use dilithium::signature::Signer;
 
 use crate::{
@@ -18,14 +19,22 @@ impl<'a> PqSigner<'a> {
         Self { keypair }
     }
 
+    /// The underlying keypair.
+    pub(crate) fn keypair(&self) -> &PqKeypair {
+        self.keypair
+    }
+
     /// Sign a transaction request and return a [`PqSignedTx`].
     pub fn sign(&self, tx: PqTxRequest) -> PqSignedTx {
-        let hash = tx.signing_hash();
-        let sig = self.keypair.sk.sign(hash.as_slice());
-        let sig_bytes = sig.encode().as_slice().to_vec();
+        let sig_bytes = self.sign_hash_bytes(tx.signing_hash());
         let pk_bytes = self.keypair.public_key_bytes();
         PqSignedTx::new(tx, sig_bytes, pk_bytes)
     }
+
+    /// Sign a 32-byte hash and return the encoded signature bytes.
+    pub(crate) fn sign_hash_bytes(&self, hash: B256) -> Vec<u8> {
+        self.keypair.sk.sign(hash.as_slice()).encode().as_slice().to_vec()
+    }
 }
 
 #[cfg(test)]
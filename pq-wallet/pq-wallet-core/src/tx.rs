@@ -33,12 +33,15 @@ pub struct PqTxRequest {
     pub nonce: u64,
     /// Recipient. `None` = contract creation.
     pub to: Option<Address>,
-    /// Value in wei.
+    /// Value in wei. Serialised as a decimal string.
+    #[serde(with = "dec_u128")]
     pub value: u128,
     pub gas_limit: u64,
-    /// Gas price in wei.
+    /// Gas price in wei. Serialised as a decimal string.
+    #[serde(with = "dec_u128")]
     pub gas_price: u128,
-    /// Calldata / init code.
+    /// Calldata / init code. Serialised as a `0x`-prefixed hex string.
+    #[serde(with = "hex_bytes")]
     pub input: Vec<u8>,
 }
 
@@ -68,6 +71,36 @@ impl PqTxRequest {
     }
 }
 
+/// Serde adapter: `Vec<u8>` <-> `0x`-prefixed hex string.
+mod hex_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(d)?;
+        hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serde adapter: `u128` <-> decimal string.
+///
+/// Wei amounts exceed 2^53, so as bare JSON numbers they would be rounded by
+/// any reader that parses numbers as doubles (jq, JavaScript).
+mod dec_u128 {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &u128, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&v.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<u128, D::Error> {
+        String::deserialize(d)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 // ─── RLP helper for encoding ─────────────────────────────────────────────────
 
 /// RLP-encodable struct matching the node's expected wire format.
@@ -130,6 +163,12 @@ impl PqSignedTx {
         out
     }
 
+    /// `0x`-prefixed hex of [`encode`](Self::encode), as expected by
+    /// `eth_sendRawTransaction`.
+    pub fn raw_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.encode()))
+    }
+
     fn compute_hash(tx: &PqTxRequest, sig: &[u8], pk: &[u8]) -> B256 {
         let mut h = Shake256::default();
         h.update(&[PQ_TX_TYPE]);
@@ -142,6 +181,20 @@ impl PqSignedTx {
     }
 }
 
+/// Transfer fixture for tests outside this module.
+#[cfg(test)]
+pub(crate) fn sample_transfer() -> PqTxRequest {
+    PqTxRequest {
+        chain_id: 20561,
+        nonce: 0,
+        to: Some(Address::from([0xab; 20])),
+        value: 1_000_000_000_000_000_000, // 1 ETH
+        gas_limit: 21_000,
+        gas_price: 1_000_000_000,
+        input: vec![],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,6 +227,27 @@ mod tests {
         assert!(encoded.len() > 5300, "encoded tx should be >5KB (sig=3309 + pk=1952 + fields)");
     }
 
+    #[test]
+    fn input_serialises_as_hex() {
+        let tx = PqTxRequest { input: vec![0xde, 0xad, 0xbe, 0xef], ..make_tx() };
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(json["input"], "0xdeadbeef");
+
+        let back: PqTxRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(back, tx);
+    }
+
+    #[test]
+    fn amounts_serialise_as_decimal_strings() {
+        let tx = PqTxRequest { value: u128::MAX, ..make_tx() };
+        let json = serde_json::to_value(&tx).unwrap();
+        assert_eq!(json["value"], u128::MAX.to_string());
+        assert_eq!(json["gas_price"], "1000000000");
+
+        let back: PqTxRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(back, tx);
+    }
+
     #[test]
     fn encode_is_valid_rlp() {
         let tx = make_tx();